@@ -4,9 +4,9 @@
 
 use crate::error;
 
-use serde_yaml::Value;
+use serde_yaml::{Mapping, Value};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ValueType {
     Null,
     Bool,
@@ -17,8 +17,38 @@ pub enum ValueType {
     Tagged,
 }
 
+impl ValueType {
+    /// Parses the name of a `ValueType` variant, as it would appear in a
+    /// `__type__` schema entry (e.g. `"Number"`)
+    fn parse(s: &str) -> Option<ValueType> {
+        match s {
+            "Null" => Some(ValueType::Null),
+            "Bool" => Some(ValueType::Bool),
+            "Number" => Some(ValueType::Number),
+            "String" => Some(ValueType::String),
+            "List" => Some(ValueType::List),
+            "Mapping" => Some(ValueType::Mapping),
+            "Tagged" => Some(ValueType::Tagged),
+            _ => None,
+        }
+    }
+}
+
+/// A violation found by [`validate`] when checking a value against a schema
+#[derive(Debug, Clone, PartialEq)]
+pub enum Violation {
+    /// A key is present in the value but has no corresponding entry in the schema
+    UnknownField { path: String },
+    /// A key marked `__required__` in the schema is missing from the value
+    MissingField { path: String },
+    /// A key's value does not match the type declared by `__type__` in the schema
+    TypeMismatch { path: String, expected: ValueType, found: ValueType },
+}
+
 const INDENT: &'static str = "    ";
 const DESCRIPTION: &'static str = "__description__";
+const TYPE: &'static str = "__type__";
+const REQUIRED: &'static str = "__required__";
 
 fn indent(content: &mut String, n: u8) {
     for _ in 0..n {
@@ -26,63 +56,161 @@ fn indent(content: &mut String, n: u8) {
     }
 }
 
-fn document_val(content: &mut String, val: &Value, description: Option<&Value>, mut indent_level: u8) -> error::Result<()> {
+/// Computes the [`ValueType`] of a `serde_yaml::Value`
+fn value_type(val: &Value) -> ValueType {
+    match val {
+        Value::Null => ValueType::Null,
+        Value::Bool(_) => ValueType::Bool,
+        Value::Number(_) => ValueType::Number,
+        Value::String(_) => ValueType::String,
+        Value::Sequence(_) => ValueType::List,
+        Value::Mapping(_) => ValueType::Mapping,
+        Value::Tagged(_) => ValueType::Tagged,
+    }
+}
+
+/// Returns the `(required, declared type)` for a schema entry, as declared by
+/// its optional `__required__`/`__type__` keys. Falls back to `(false, None)`
+/// for the plain-string description entries `document()` already supports.
+fn schema_meta(entry: &Value) -> (bool, Option<ValueType>) {
+    match entry {
+        Value::Mapping(m) => {
+            let required = m.get(&Value::String(REQUIRED.to_owned()))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            let ty = m.get(&Value::String(TYPE.to_owned()))
+                .and_then(|v| v.as_str())
+                .and_then(ValueType::parse);
+            (required, ty)
+        }
+        _ => (false, None),
+    }
+}
+
+fn key_to_string(key: &Value) -> String {
+    if key.is_string() {
+        key.as_str().unwrap().to_owned()
+    } else {
+        format!("{:?}", key)
+    }
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn validate_val(val: &Value, schema: Option<&Value>, path: &str, violations: &mut Vec<Violation>) {
+    let m = match val.as_mapping() {
+        Some(m) => m,
+        None => return,
+    };
+    let schema_map = schema.and_then(|s| s.as_mapping());
+
+    for (key, value) in m.iter() {
+        let child_path = join_path(path, &key_to_string(key));
+        match schema_map.and_then(|sm| sm.get(key)) {
+            None => violations.push(Violation::UnknownField { path: child_path }),
+            Some(entry) => {
+                let (_, expected) = schema_meta(entry);
+                let mismatched = match expected {
+                    Some(expected) if value_type(value) != expected => {
+                        violations.push(Violation::TypeMismatch { path: child_path.clone(), expected, found: value_type(value) });
+                        true
+                    }
+                    _ => false,
+                };
+                if value.is_mapping() && !mismatched {
+                    validate_val(value, Some(entry), &child_path, violations);
+                }
+            }
+        }
+    }
+
+    if let Some(sm) = schema_map {
+        for (skey, sentry) in sm.iter() {
+            if skey.as_str() == Some(DESCRIPTION) || skey.as_str() == Some(TYPE) || skey.as_str() == Some(REQUIRED) {
+                continue;
+            }
+            let (required, _) = schema_meta(sentry);
+            if required && m.get(skey).is_none() {
+                violations.push(Violation::MissingField { path: join_path(path, &key_to_string(skey)) });
+            }
+        }
+    }
+}
+
+/// Validates `val` against `schema`, an authoritative description tree of the same
+/// shape `document()` uses for its `__description__` conventions.
+///
+/// Each schema entry may be a plain string (as accepted by `document()`, in which
+/// case it carries no type/required information), or a mapping of the form
+/// `{ __description__: "...", __type__: "Number", __required__: true }`. Any key
+/// present in `val` without a matching schema entry is reported as
+/// [`Violation::UnknownField`], any `__required__` schema key missing from `val`
+/// is reported as [`Violation::MissingField`], and a declared `__type__` that
+/// disagrees with the value actually found is reported as
+/// [`Violation::TypeMismatch`]. All violations are collected; validation does not
+/// stop at the first one.
+pub fn validate(val: &Value, schema: &Value) -> error::Result<Vec<Violation>> {
+    let mut violations = Vec::new();
+    validate_val(val, Some(schema), "", &mut violations);
+    Ok(violations)
+}
+
+/// Selects the rendering backend used by [`document_with`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DocFormat {
+    /// The original bespoke indented/commented text format
+    Plain,
+    /// Markdown suitable for a README or a Pandoc/Markdown pipeline
+    Markdown,
+}
+
+/// Extracts the description string carried by a description-tree entry, whether
+/// it is a plain string or a mapping with a `__description__` key
+fn description_text(desc_value: Option<&Value>) -> Option<&str> {
+    match desc_value {
+        Some(Value::String(s)) => Some(s.as_str()),
+        Some(Value::Mapping(m)) => m.get(DESCRIPTION).and_then(|v| v.as_str()),
+        _ => None,
+    }
+}
+
+fn document_val(content: &mut String, val: &Value, description: Option<&Value>, indent_level: u8, format: DocFormat) -> error::Result<()> {
+    match format {
+        DocFormat::Plain => document_val_plain(content, val, description, indent_level),
+        DocFormat::Markdown => document_val_markdown(content, val, description, indent_level),
+    }
+}
+
+fn document_val_plain(content: &mut String, val: &Value, description: Option<&Value>, indent_level: u8) -> error::Result<()> {
     match val {
         Value::Mapping(ref m) => {
             if indent_level > 0 {
                 content.push_str("\n");
             }
             for (key, value) in m.iter() {
-                let ty = match value {
-                    Value::Null => ValueType::Null,
-                    Value::Bool(_) => ValueType::Bool,
-                    Value::Number(_) => ValueType::Number,
-                    Value::String(_) => ValueType::String,
-                    Value::Sequence(_) => ValueType::List,
-                    Value::Mapping(_) => ValueType::Mapping,
-                    Value::Tagged(_) => ValueType::Tagged,
-                };
+                let ty = value_type(value);
                 // Try displaying the description, if it exists
                 let desc_value = description.and_then(|d| d.as_mapping())
                     .and_then(|m| m.get(key));
-                if let Some(inner) = desc_value {
-                    match inner {
-                        Value::String(s) => {
-                            // Found a description, displays it
-                            indent(content, indent_level);
-                            content.push_str("# ");
-                            content.push_str(s);
-                            content.push_str("\n");
-                        }
-                        Value::Mapping(m) => {
-                            // Try to see if there is a description field for this mapping
-                            let desc = m.get(DESCRIPTION)
-                                .and_then(|v| v.as_str());
-                            if let Some(s) = desc {
-                                indent(content, indent_level);
-                                content.push_str("# ");
-                                content.push_str(s);
-                                content.push_str("\n");
-                            }
-                        }
-                        _ => {
-
-                        }
-                    }
+                if let Some(s) = description_text(desc_value) {
+                    indent(content, indent_level);
+                    content.push_str("# ");
+                    content.push_str(s);
+                    content.push_str("\n");
                 }
-                
-                
+
                 // Display the key name
                 for _ in 0..indent_level {
                     content.push_str(INDENT);
                 }
-                let k = if key.is_string() {
-                    key.as_str().unwrap().to_owned()
-                } else {
-                    format!("{:?}", key)
-                };
-                content.push_str(&format!("{} ({:?}): ", &k, ty));
-                document_val(content, value, desc_value, indent_level + 1);
+                content.push_str(&format!("{} ({:?}): ", &key_to_string(key), ty));
+                document_val_plain(content, value, desc_value, indent_level + 1)?;
             }
         },
         Value::Sequence(ref s) => {
@@ -90,14 +218,76 @@ fn document_val(content: &mut String, val: &Value, description: Option<&Value>,
             for v in s.iter() {
                 indent(content, indent_level);
                 content.push_str("- ");
-                document_val(content, v, None, indent_level + 1);
+                document_val_plain(content, v, None, indent_level + 1)?;
             }
         }
         _ => {
             content.push_str(&serde_yaml::to_string(val)?);
         },
     }
-    Ok(())    
+    Ok(())
+}
+
+/// Renders a scalar as inline Markdown code, trimming the trailing newline
+/// `serde_yaml::to_string` always appends
+fn markdown_scalar(val: &Value) -> error::Result<String> {
+    Ok(format!("`{}`", serde_yaml::to_string(val)?.trim_end()))
+}
+
+fn document_val_markdown(content: &mut String, val: &Value, description: Option<&Value>, indent_level: u8) -> error::Result<()> {
+    match val {
+        Value::Mapping(ref m) => {
+            for (key, value) in m.iter() {
+                let ty = value_type(value);
+                let desc_value = description.and_then(|d| d.as_mapping())
+                    .and_then(|m| m.get(key));
+
+                indent(content, indent_level);
+                content.push_str(&format!("- `{}` *{:?}*", key_to_string(key), ty));
+
+                match value {
+                    Value::Mapping(_) | Value::Sequence(_) => {
+                        if let Some(s) = description_text(desc_value) {
+                            content.push_str(" — ");
+                            content.push_str(s);
+                        }
+                        content.push_str("\n");
+                        document_val_markdown(content, value, desc_value, indent_level + 1)?;
+                    }
+                    _ => {
+                        content.push_str(": ");
+                        content.push_str(&markdown_scalar(value)?);
+                        if let Some(s) = description_text(desc_value) {
+                            content.push_str(" — ");
+                            content.push_str(s);
+                        }
+                        content.push_str("\n");
+                    }
+                }
+            }
+        },
+        Value::Sequence(ref s) => {
+            for v in s.iter() {
+                indent(content, indent_level);
+                content.push_str("- ");
+                match v {
+                    Value::Mapping(_) | Value::Sequence(_) => {
+                        content.push_str("\n");
+                        document_val_markdown(content, v, None, indent_level + 1)?;
+                    }
+                    _ => {
+                        content.push_str(&markdown_scalar(v)?);
+                        content.push_str("\n");
+                    }
+                }
+            }
+        }
+        _ => {
+            content.push_str(&markdown_scalar(val)?);
+            content.push_str("\n");
+        },
+    }
+    Ok(())
 }
 
 
@@ -131,13 +321,197 @@ fn document_val(content: &mut String, val: &Value, description: Option<&Value>,
 ///         assert_eq!(s, expected);
 /// ```
 pub fn document(val: &Value, description: Option<&Value>) -> error::Result<String> {
+    document_with(val, description, DocFormat::Plain)
+}
+
+/// Like [`document`], but renders using the given [`DocFormat`] instead of
+/// always falling back to the original indented text format.
+///
+/// `DocFormat::Markdown` renders each mapping level as a nested bullet with the
+/// key in a code span and the [`ValueType`] as an emphasized type annotation,
+/// and leaf scalars as inline code, so the result can be dropped directly into a
+/// README or a Pandoc/Markdown pipeline.
+pub fn document_with(val: &Value, description: Option<&Value>, format: DocFormat) -> error::Result<String> {
     let mut content = String::new();
 
-    document_val(&mut content, val, description, 0)?;
-    
+    document_val(&mut content, val, description, 0, format)?;
+
     Ok(content)
 }
 
+/// Inserts `value` at the dotted path `parts` (rooted at `prefix`) into
+/// `mapping`, descending into (and creating, as needed) nested mappings,
+/// merging siblings that share a prefix along the way.
+///
+/// Errors if a path is used both as a scalar/sequence and as the parent of
+/// another path, since the two can't be reconciled into a single tree.
+fn insert_path(mapping: &mut Mapping, prefix: &str, parts: &[&str], value: Value) -> error::Result<()> {
+    let path = join_path(prefix, parts[0]);
+    let key = Value::String(parts[0].to_owned());
+    if parts.len() == 1 {
+        if let Some(Value::Mapping(existing)) = mapping.get_mut(&key) {
+            match value {
+                // Both sides are mappings: merge them key by key
+                Value::Mapping(new) => {
+                    for (k, v) in new {
+                        match k.as_str().map(str::to_owned) {
+                            Some(s) => insert_path(existing, &path, &[s.as_str()], v)?,
+                            None => { existing.insert(k, v); }
+                        }
+                    }
+                    return Ok(());
+                }
+                _ => {
+                    return Err(error::Error::Msg(format!(
+                        "can't expand '{}': it is used both as a value and as a parent key",
+                        path
+                    )));
+                }
+            }
+        }
+        mapping.insert(key, value);
+    } else {
+        let child = mapping.entry(key).or_insert_with(|| Value::Mapping(Mapping::new()));
+        match child {
+            Value::Mapping(child) => insert_path(child, &path, &parts[1..], value)?,
+            _ => {
+                return Err(error::Error::Msg(format!(
+                    "can't expand '{}': it is used both as a value and as a parent key",
+                    path
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Expands a mapping whose string keys contain `.` (e.g. `foo.bar.baz: 42`)
+/// into the nested mapping tree the dotted keys describe, merging siblings
+/// that share a prefix.
+///
+/// Keys without a `.` are copied over as-is, except that a mapping value is
+/// merged into a mapping that dotted keys already built under the same name.
+/// Errors if a dotted path would
+/// need to turn an existing scalar/sequence into a parent mapping, or
+/// vice-versa.
+pub fn expand(val: &Value) -> error::Result<Value> {
+    match val {
+        Value::Mapping(m) => {
+            let mut out = Mapping::new();
+            for (key, value) in m.iter() {
+                let value = expand(value)?;
+                match key.as_str() {
+                    Some(s) if s.contains('.') => {
+                        let parts: Vec<&str> = s.split('.').collect();
+                        insert_path(&mut out, "", &parts, value)?;
+                    }
+                    Some(s) => insert_path(&mut out, "", &[s], value)?,
+                    None => {
+                        out.insert(key.clone(), value);
+                    }
+                }
+            }
+            Ok(Value::Mapping(out))
+        }
+        _ => Ok(val.clone()),
+    }
+}
+
+/// Flattens a nested mapping into a single mapping whose string keys join
+/// the path to each leaf with `.`, the inverse of [`expand`].
+pub fn flatten(val: &Value) -> error::Result<Value> {
+    let mut out = Mapping::new();
+    if let Value::Mapping(m) = val {
+        if m.is_empty() {
+            return Ok(Value::Mapping(out));
+        }
+    }
+    flatten_into(&mut out, "", val)?;
+    Ok(Value::Mapping(out))
+}
+
+fn flatten_into(out: &mut Mapping, prefix: &str, val: &Value) -> error::Result<()> {
+    match val {
+        Value::Mapping(m) if !m.is_empty() => {
+            for (key, value) in m.iter() {
+                let key_str = key_to_string(key);
+                let path = join_path(prefix, &key_str);
+                flatten_into(out, &path, value)?;
+            }
+        }
+        _ => {
+            out.insert(Value::String(prefix.to_owned()), val.clone());
+        }
+    }
+    Ok(())
+}
+
+fn merge_two(base: &Value, overlay: &Value) -> Value {
+    match (base, overlay) {
+        (Value::Mapping(b), Value::Mapping(o)) => {
+            let mut out = b.clone();
+            for (key, value) in o.iter() {
+                let merged = match out.get(key) {
+                    Some(existing) => merge_two(existing, value),
+                    None => value.clone(),
+                };
+                out.insert(key.clone(), merged);
+            }
+            Value::Mapping(out)
+        }
+        _ => overlay.clone(),
+    }
+}
+
+/// Deep-merges a sequence of YAML mappings left-to-right: later layers
+/// override earlier ones key-by-key, recursing into nested mappings and
+/// replacing scalars and sequences wholesale.
+///
+/// Combined with [`expand`], a thin override layer like
+/// `{ "server.port": 9000 }` can be expanded and merged on top of a base
+/// config, the way config stacks compose defaults, file, and environment
+/// sources into one effective document.
+pub fn merge(layers: &[&Value]) -> error::Result<Value> {
+    let mut out = Value::Mapping(Mapping::new());
+    for layer in layers {
+        out = merge_two(&out, layer);
+    }
+    Ok(out)
+}
+
+/// Extracts a leading YAML front-matter block from `text` and parses it into
+/// a `Value`, ready to pass as the `description` argument to [`document`].
+///
+/// The block must start on the very first line with `---` and is terminated
+/// by a line consisting of `---` or `...`, the leading-YAML convention used
+/// by Markdown document toolchains. Returns `Ok(None)` if `text` has no such
+/// block, so field descriptions can be kept alongside narrative docs in a
+/// single Markdown file instead of a separate description YAML.
+pub fn description_from_front_matter(text: &str) -> error::Result<Option<Value>> {
+    let mut lines = text.lines();
+    match lines.next() {
+        Some(first) if first.trim() == "---" => (),
+        _ => return Ok(None),
+    }
+
+    let mut yaml = String::new();
+    for line in lines {
+        match line.trim() {
+            "---" | "..." => {
+                let value: Value = serde_yaml::from_str(&yaml)?;
+                return Ok(Some(value));
+            }
+            _ => {
+                yaml.push_str(line);
+                yaml.push('\n');
+            }
+        }
+    }
+
+    // Unterminated block: no closing `---`/`...` found
+    Ok(None)
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -168,4 +542,217 @@ foo (Mapping):
         let s = document(&value, Some(&desc)).unwrap();
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn document_markdown() {
+        let desc_yaml = r#"
+foo:
+    __description__: Description for foo
+    bar: Description for bar
+"#;
+
+        let yaml = r#"
+foo:
+    bar: 42
+"#;
+
+        let expected = r#"- `foo` *Mapping* — Description for foo
+    - `bar` *Number*: `42` — Description for bar
+"#;
+        let value: Value = serde_yaml::from_str(&yaml).unwrap();
+        let desc: Value = serde_yaml::from_str(&desc_yaml).unwrap();
+        let s = document_with(&value, Some(&desc), DocFormat::Markdown).unwrap();
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn validate_schema() {
+        let schema_yaml = r#"
+foo:
+    __description__: Description for foo
+    __required__: true
+    bar:
+        __description__: Description for bar
+        __type__: Number
+        __required__: true
+baz:
+    __type__: String
+    __required__: true
+"#;
+
+        let yaml = r#"
+foo:
+    bar: "not a number"
+    quux: 1
+"#;
+
+        let schema: Value = serde_yaml::from_str(&schema_yaml).unwrap();
+        let value: Value = serde_yaml::from_str(&yaml).unwrap();
+        let mut violations = validate(&value, &schema).unwrap();
+        violations.sort_by_key(|v| match v {
+            Violation::UnknownField { path } => path.clone(),
+            Violation::MissingField { path } => path.clone(),
+            Violation::TypeMismatch { path, .. } => path.clone(),
+        });
+
+        assert_eq!(violations, vec![
+            Violation::MissingField { path: "baz".to_owned() },
+            Violation::TypeMismatch {
+                path: "foo.bar".to_owned(),
+                expected: ValueType::Number,
+                found: ValueType::String,
+            },
+            Violation::UnknownField { path: "foo.quux".to_owned() },
+        ]);
+    }
+
+    #[test]
+    fn validate_type_mismatch_does_not_cascade() {
+        let schema_yaml = r#"
+foo:
+    __type__: String
+"#;
+
+        let yaml = r#"
+foo:
+    bar: 1
+    baz: 2
+"#;
+
+        let schema: Value = serde_yaml::from_str(&schema_yaml).unwrap();
+        let value: Value = serde_yaml::from_str(&yaml).unwrap();
+        let violations = validate(&value, &schema).unwrap();
+
+        assert_eq!(violations, vec![
+            Violation::TypeMismatch {
+                path: "foo".to_owned(),
+                expected: ValueType::String,
+                found: ValueType::Mapping,
+            },
+        ]);
+    }
+
+    #[test]
+    fn expand_dotted_keys() {
+        let yaml = r#"
+foo.bar.baz: 42
+foo.bar.quux: 43
+top: 1
+"#;
+        let expected = r#"
+foo:
+    bar:
+        baz: 42
+        quux: 43
+top: 1
+"#;
+        let value: Value = serde_yaml::from_str(&yaml).unwrap();
+        let expected: Value = serde_yaml::from_str(&expected).unwrap();
+        assert_eq!(expand(&value).unwrap(), expected);
+    }
+
+    #[test]
+    fn expand_conflict() {
+        let yaml = r#"
+foo: 1
+foo.bar: 2
+"#;
+        let value: Value = serde_yaml::from_str(&yaml).unwrap();
+        assert!(expand(&value).is_err());
+    }
+
+    #[test]
+    fn expand_conflict_names_full_path() {
+        let yaml = r#"
+a.b: 1
+a.b.c: 2
+"#;
+        let value: Value = serde_yaml::from_str(&yaml).unwrap();
+        let err = expand(&value).unwrap_err().to_string();
+        assert!(err.contains("a.b"), "error should name the full conflicting path, got: {}", err);
+    }
+
+    #[test]
+    fn flatten_roundtrip() {
+        let yaml = r#"
+foo:
+    bar:
+        baz: 42
+        quux: 43
+top: 1
+"#;
+        let value: Value = serde_yaml::from_str(&yaml).unwrap();
+        let flat = flatten(&value).unwrap();
+        assert_eq!(expand(&flat).unwrap(), value);
+    }
+
+    #[test]
+    fn flatten_roundtrip_with_empty_mapping() {
+        let yaml = r#"
+foo: {}
+bar: 1
+"#;
+        let value: Value = serde_yaml::from_str(&yaml).unwrap();
+        let flat = flatten(&value).unwrap();
+        assert_eq!(expand(&flat).unwrap(), value);
+    }
+
+    #[test]
+    fn flatten_empty_root() {
+        let value = Value::Mapping(Mapping::new());
+        assert_eq!(flatten(&value).unwrap(), value);
+    }
+
+    #[test]
+    fn merge_layers_with_override() {
+        let base: Value = serde_yaml::from_str(r#"
+server:
+    host: localhost
+    port: 8080
+log:
+    level: info
+"#).unwrap();
+        let override_layer = expand(&serde_yaml::from_str(r#"
+"server.port": 9000
+"#).unwrap()).unwrap();
+
+        let expected: Value = serde_yaml::from_str(r#"
+server:
+    host: localhost
+    port: 9000
+log:
+    level: info
+"#).unwrap();
+
+        let merged = merge(&[&base, &override_layer]).unwrap();
+        assert_eq!(merged, expected);
+    }
+
+    #[test]
+    fn front_matter_description() {
+        let text = r#"---
+foo:
+    __description__: Description for foo
+    bar: Description for bar
+---
+
+# My options
+
+Some narrative documentation.
+"#;
+        let expected: Value = serde_yaml::from_str(r#"
+foo:
+    __description__: Description for foo
+    bar: Description for bar
+"#).unwrap();
+
+        let desc = description_from_front_matter(text).unwrap();
+        assert_eq!(desc, Some(expected));
+    }
+
+    #[test]
+    fn front_matter_missing() {
+        let text = "# Just a heading\n\nNo front matter here.\n";
+        assert_eq!(description_from_front_matter(text).unwrap(), None);
+    }
 }
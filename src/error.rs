@@ -0,0 +1,35 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::error;
+use std::fmt;
+
+/// The error type used throughout this crate
+#[derive(Debug)]
+pub enum Error {
+    /// An error coming from (de)serializing YAML
+    Yaml(serde_yaml::Error),
+    /// A conflict or inconsistency found while restructuring a YAML value
+    Msg(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Yaml(e) => write!(f, "{}", e),
+            Error::Msg(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<serde_yaml::Error> for Error {
+    fn from(error: serde_yaml::Error) -> Error {
+        Error::Yaml(error)
+    }
+}
+
+/// A specialized `Result` type used throughout this crate
+pub type Result<T> = std::result::Result<T, Error>;